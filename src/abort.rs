@@ -0,0 +1,212 @@
+use core::{cell::UnsafeCell, sync::atomic::{AtomicBool, Ordering}, task::Waker};
+
+use alloc::sync::Arc;
+
+/// Error returned by an abortable combinator when it was aborted before it could finish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aborted;
+
+/// A single-slot `Waker` cell guarded by a spinlock, so [AbortHandle]/[AbortRegistration] stay
+/// usable in `no_std` + `alloc` (no `std::sync::Mutex` available), which is what lets `async`
+/// be enabled without `std`.
+struct WakerSlot
+{
+    locked: AtomicBool,
+    waker: UnsafeCell<Option<Waker>>
+}
+
+// SAFETY: `locked` gates all access to `waker`, so the cell is only ever touched by one thread
+// at a time.
+unsafe impl Send for WakerSlot {}
+unsafe impl Sync for WakerSlot {}
+
+impl WakerSlot
+{
+    const fn new() -> Self
+    {
+        Self {
+            locked: AtomicBool::new(false),
+            waker: UnsafeCell::new(None)
+        }
+    }
+
+    fn with<R>(&self, f: impl FnOnce(&mut Option<Waker>) -> R) -> R
+    {
+        while self.locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+
+        // SAFETY: we just acquired the lock above, and release it unconditionally below.
+        let result = f(unsafe { &mut *self.waker.get() });
+
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+struct Inner
+{
+    aborted: AtomicBool,
+    waker: WakerSlot
+}
+
+/// A handle used to abort an associated abortable zipped call.
+///
+/// Dropping the handle without calling [abort](AbortHandle::abort) leaves the call to run to
+/// completion as normal.
+#[derive(Clone)]
+pub struct AbortHandle
+{
+    inner: Arc<Inner>
+}
+
+impl AbortHandle
+{
+    /// Signals the associated call to abort, waking it up if it is currently being polled.
+    pub fn abort(&self)
+    {
+        self.inner.aborted.store(true, Ordering::Release);
+        if let Some(waker) = self.inner.waker.with(Option::take)
+        {
+            waker.wake();
+        }
+    }
+
+    pub fn is_aborted(&self) -> bool
+    {
+        self.inner.aborted.load(Ordering::Acquire)
+    }
+}
+
+/// The other half of an [AbortHandle], held by the abortable combinator itself.
+pub(crate) struct AbortRegistration
+{
+    inner: Arc<Inner>
+}
+
+impl AbortRegistration
+{
+    pub(crate) fn new_pair() -> (AbortHandle, AbortRegistration)
+    {
+        let inner = Arc::new(Inner {
+            aborted: AtomicBool::new(false),
+            waker: WakerSlot::new()
+        });
+
+        (
+            AbortHandle { inner: inner.clone() },
+            AbortRegistration { inner }
+        )
+    }
+
+    pub(crate) fn is_aborted(&self) -> bool
+    {
+        self.inner.aborted.load(Ordering::Acquire)
+    }
+
+    /// Registers the waker of the task currently polling the abortable combinator, so that
+    /// a later call to [AbortHandle::abort] can wake it back up.
+    pub(crate) fn register(&self, waker: &Waker)
+    {
+        self.inner.waker.with(|slot| *slot = Some(waker.clone()));
+    }
+}
+
+#[cfg(feature = "async")]
+mod join
+{
+    use core::{future::Future, pin::Pin, task::{Context, Poll}};
+
+    use super::{Aborted, AbortHandle, AbortRegistration};
+
+    /// Wraps a future so that it can be cancelled through an [AbortHandle] while it is being
+    /// polled, resolving to [Aborted] instead of its usual output if it is.
+    ///
+    /// Used to make joined futures such as [Join](crate::Join) abortable as a unit, since
+    /// aborting only one of their arms isn't otherwise expressible.
+    pub struct AbortableJoin<F>
+    where
+        F: Future
+    {
+        inner: F,
+        registration: AbortRegistration
+    }
+
+    impl<F> AbortableJoin<F>
+    where
+        F: Future
+    {
+        pub(crate) fn new(inner: F) -> (Self, AbortHandle)
+        {
+            let (handle, registration) = AbortRegistration::new_pair();
+            (Self { inner, registration }, handle)
+        }
+    }
+
+    impl<F> Future for AbortableJoin<F>
+    where
+        F: Future
+    {
+        type Output = Result<F::Output, Aborted>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+        {
+            // SAFETY: pinning is structural for `inner`; `registration` is never moved out from
+            // behind the pin.
+            let this = unsafe { self.get_unchecked_mut() };
+
+            if this.registration.is_aborted()
+            {
+                return Poll::Ready(Err(Aborted));
+            }
+            this.registration.register(cx.waker());
+
+            // Re-check after registering: if `abort()` ran on another thread between the check
+            // above and the registration just now, its `wake()` call may have found no waker
+            // registered yet and been a no-op. Catch that here instead of silently missing the
+            // wakeup until the inner future happens to make progress on its own.
+            if this.registration.is_aborted()
+            {
+                return Poll::Ready(Err(Aborted));
+            }
+
+            match unsafe { Pin::new_unchecked(&mut this.inner) }.poll(cx)
+            {
+                Poll::Ready(val) => Poll::Ready(Ok(val)),
+                Poll::Pending => Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use join::AbortableJoin;
+
+/// A handle that cancels its associated [AbortableJoin] when dropped, rather than requiring an
+/// explicit call to [AbortHandle::abort].
+///
+/// Returned by [Join::remote_handle](crate::Join::remote_handle), for zipped work that must be
+/// abortable as a unit for as long as the handle is kept alive.
+#[cfg(feature = "async")]
+pub struct RemoteHandle(AbortHandle);
+
+#[cfg(feature = "async")]
+impl Drop for RemoteHandle
+{
+    fn drop(&mut self)
+    {
+        self.0.abort();
+    }
+}
+
+#[cfg(feature = "async")]
+impl RemoteHandle
+{
+    pub(crate) fn new(handle: AbortHandle) -> Self
+    {
+        Self(handle)
+    }
+}
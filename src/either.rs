@@ -0,0 +1,17 @@
+/// The result of a racing combinator such as [FnSelect::fn_select](FnSelect::fn_select) or
+/// [ZippedFnPar::select_once](crate::ZippedFnPar::select_once), telling the caller which side
+/// finished first.
+///
+/// This mirrors the `Either` type from the futures ecosystem.
+///
+/// What's carried alongside the winning value depends on the combinator: the async
+/// [Select](crate::Select)-based ones pair it with the still-unfinished other side (so the
+/// caller can keep driving or drop it), while [ZippedFnPar::select_once](crate::ZippedFnPar::select_once)
+/// just holds the winning value, since its losing thread is always joined before returning.
+pub enum Either<A, B>
+{
+    /// The left side finished first.
+    Left(A),
+    /// The right side finished first.
+    Right(B)
+}
@@ -0,0 +1,13 @@
+use core::future::Future;
+
+/// A future that can report whether it has already resolved.
+///
+/// This mirrors `futures_core::future::FusedFuture`, reimplemented locally so that depending on
+/// this crate doesn't pull in the `futures` family of crates just for the one trait. Combinators
+/// that implement it are safe to keep polling after they resolve (e.g. inside a `select!`-style
+/// loop or a `FuturesUnordered`), rather than re-driving already-finished inner futures.
+pub trait FusedFuture: Future
+{
+    /// Returns `true` once this future has produced its output and has nothing left to do.
+    fn is_terminated(&self) -> bool;
+}
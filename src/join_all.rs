@@ -0,0 +1,61 @@
+use core::{future::Future, pin::Pin, task::{Context, Poll}};
+
+use alloc::vec::Vec;
+
+use crate::maybe_done::MaybeDone;
+
+/// A collection of joined futures of the same type, resolving to the `Vec` of their outputs
+/// in input order once every one of them has completed.
+///
+/// This is the variadic analog of [Join](crate::Join), modeled on futures-util's `join_all`.
+pub struct JoinAll<F: Future>
+{
+    entries: Vec<MaybeDone<F>>
+}
+
+impl<F: Future> JoinAll<F>
+{
+    pub fn new<I>(futures: I) -> Self
+    where
+        I: IntoIterator<Item = F>
+    {
+        Self {
+            entries: futures.into_iter().map(MaybeDone::Future).collect()
+        }
+    }
+}
+
+impl<F: Future> Future for JoinAll<F>
+{
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        // Like `Join`, every not-yet-done entry is polled each wakeup, regardless of whether
+        // an earlier entry in the collection is still pending.
+        let entries = unsafe {
+            &mut self.as_mut()
+                .get_unchecked_mut()
+                .entries
+        };
+
+        let mut all_done = true;
+        for entry in entries.iter_mut()
+        {
+            // SAFETY: entries are never moved out of the `Vec` while pinned.
+            if unsafe { Pin::new_unchecked(entry) }.poll(cx).is_pending()
+            {
+                all_done = false;
+            }
+        }
+
+        if !all_done
+        {
+            return Poll::Pending
+        }
+
+        Poll::Ready(entries.iter_mut()
+            .map(|entry| entry.take_output().unwrap())
+            .collect())
+    }
+}
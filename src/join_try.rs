@@ -0,0 +1,89 @@
+use core::{future::Future, pin::Pin, task::{Context, Poll}};
+
+use crate::maybe_done::MaybeDone;
+
+/// A pair of joined fallible futures that short-circuits to the first `Err`, rather than
+/// waiting for both to finish like [Join](crate::Join).
+///
+/// This is really only for use with the [ZippedFnTry](crate::ZippedFnTry) struct.
+/// If you need to try-join futures normally, use `tokio::try_join!`.
+pub struct TryJoinedPair<L, R>
+where
+    L: Future,
+    R: Future
+{
+    left: MaybeDone<L>,
+    right: MaybeDone<R>
+}
+
+impl<L, R> TryJoinedPair<L, R>
+where
+    L: Future,
+    R: Future
+{
+    pub fn new(left: L, right: R) -> Self
+    {
+        Self {
+            left: MaybeDone::Future(left),
+            right: MaybeDone::Future(right)
+        }
+    }
+}
+
+impl<L, R, T, U, E> Future for TryJoinedPair<L, R>
+where
+    L: Future<Output = Result<T, E>>,
+    R: Future<Output = Result<U, E>>
+{
+    type Output = Result<(T, U), E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        let pair = unsafe {
+            self.as_mut()
+                .get_unchecked_mut()
+        };
+
+        if !matches!(pair.left, MaybeDone::Done(_))
+        {
+            let _ = unsafe { Pin::new_unchecked(&mut pair.left) }.poll(cx);
+        }
+        if matches!(pair.left, MaybeDone::Done(Err(_)))
+        {
+            let Some(Err(e)) = pair.left.take_output()
+            else
+            {
+                unreachable!()
+            };
+            // The still-pending `right` future is simply dropped along with `self`.
+            return Poll::Ready(Err(e))
+        }
+
+        if !matches!(pair.right, MaybeDone::Done(_))
+        {
+            let _ = unsafe { Pin::new_unchecked(&mut pair.right) }.poll(cx);
+        }
+        if matches!(pair.right, MaybeDone::Done(Err(_)))
+        {
+            let Some(Err(e)) = pair.right.take_output()
+            else
+            {
+                unreachable!()
+            };
+            return Poll::Ready(Err(e))
+        }
+
+        if !matches!(pair.left, MaybeDone::Done(_)) || !matches!(pair.right, MaybeDone::Done(_))
+        {
+            return Poll::Pending
+        }
+
+        let (Some(Ok(t)), Some(Ok(u))) = (pair.left.take_output(), pair.right.take_output())
+        else
+        {
+            unreachable!()
+        };
+
+        Poll::Ready(Ok((t, u)))
+    }
+}
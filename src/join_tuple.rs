@@ -0,0 +1,73 @@
+use core::{future::Future, pin::Pin, task::{Context, Poll}};
+
+use crate::maybe_done::MaybeDone;
+
+macro_rules! impl_joined_tuple {
+    ($name:ident; $($f:ident : $F:ident),+) => {
+        /// A flat join of several futures of possibly-different types, resolving to a tuple of
+        /// all of their outputs once every one of them has completed.
+        ///
+        /// Unlike nesting [Join](crate::Join) pairwise, every arm is stored and polled directly
+        /// in one flat struct, and the output is a single flat tuple rather than nested pairs.
+        pub struct $name<$($F),+>
+        where
+            $($F: Future),+
+        {
+            $($f: MaybeDone<$F>),+
+        }
+
+        impl<$($F),+> $name<$($F),+>
+        where
+            $($F: Future),+
+        {
+            #[allow(clippy::too_many_arguments)]
+            pub fn new($($f: $F),+) -> Self
+            {
+                Self {
+                    $($f: MaybeDone::Future($f)),+
+                }
+            }
+        }
+
+        impl<$($F),+> Future for $name<$($F),+>
+        where
+            $($F: Future),+
+        {
+            type Output = ($($F::Output),+);
+
+            fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+            {
+                let state = unsafe {
+                    self.as_mut()
+                        .get_unchecked_mut()
+                };
+
+                let mut all_done = true;
+                $(
+                    if !matches!(state.$f, MaybeDone::Done(_))
+                    {
+                        // SAFETY: fields are never moved out of `state` while pinned.
+                        if unsafe { Pin::new_unchecked(&mut state.$f) }.poll(cx).is_pending()
+                        {
+                            all_done = false;
+                        }
+                    }
+                )+
+
+                if !all_done
+                {
+                    return Poll::Pending
+                }
+
+                Poll::Ready(($(state.$f.take_output().unwrap()),+))
+            }
+        }
+    };
+}
+
+impl_joined_tuple!(JoinedTuple3; f0: F0, f1: F1, f2: F2);
+impl_joined_tuple!(JoinedTuple4; f0: F0, f1: F1, f2: F2, f3: F3);
+impl_joined_tuple!(JoinedTuple5; f0: F0, f1: F1, f2: F2, f3: F3, f4: F4);
+impl_joined_tuple!(JoinedTuple6; f0: F0, f1: F1, f2: F2, f3: F3, f4: F4, f5: F5);
+impl_joined_tuple!(JoinedTuple7; f0: F0, f1: F1, f2: F2, f3: F3, f4: F4, f5: F5, f6: F6);
+impl_joined_tuple!(JoinedTuple8; f0: F0, f1: F1, f2: F2, f3: F3, f4: F4, f5: F5, f6: F6, f7: F7);
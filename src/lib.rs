@@ -5,6 +5,9 @@
 #![feature(fn_traits)]
 #![cfg_attr(feature = "async", feature(async_fn_traits))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 //! Provides a zip trait for functions, allowing two functions to be combined at compile-time before being called.
 //! This is equivalent to `core::future::join!()`, but lazy, and works for non-async functions.
 //!
@@ -108,12 +111,79 @@ assert_eq!(y_b, b(x_b).await);
 //! By default, this crate operates with function pairs of up to 16 arguments combined, and splits them up in the form of tuples. If you want to use differently sized tuples, use the features `8`, `16`, `32`, `64`, `96`, `128`, `160`, `192`, `224` or `256` to set the maximum supported tuple size.
 //! 
 //! The `dont_hurt_yourself_by_using_all_features` is there to prevent usage of tuples bigger than 8 if `cargo` is ran with the flag `--all-features`. Using a tuple size above 16 is highly discouraged as it will make compilation time unbearably long. Compilation time will increase exponentially. You have been warned.
+//!
+//! # Zipping many functions
+//!
+//! If all of the functions share the same argument tuple and output type, an array (or iterator) of them can be zipped at once with [FnZipAll]/[FnZipAllIter], rather than nesting [fn_zip](FnZip::fn_zip) calls pairwise.
+//!
+//! ```rust
+//! use fn_zip::FnZipAll;
+//!
+//! fn square(x: i32) -> i32
+//! {
+//!     x * x
+//! }
+//!
+//! let all = [square, square, square].fn_zip_all();
+//!
+//! assert_eq!(all.call([(1,), (2,), (3,)]), [1, 4, 9]);
+//! ```
+//!
+//! Requires the `alloc` feature for the iterator-based [FnZipAllIter]. Any `async` use of
+//! [FnZipAll] also requires `alloc`, even for the fixed-size array case, since [JoinAll]
+//! always collects its arms into a `Vec`.
+//!
+//! # Zipping three or more functions at once
+//!
+//! Nesting [fn_zip](FnZip::fn_zip) pairwise to combine three or more functions of different
+//! signatures works, but yields a nested output tuple such as `((y_a, y_b), y_c)`.
+//! The [fn_zip!] macro avoids the nesting entirely, for up to eight functions at a time.
+//!
+//! ```rust
+//! use fn_zip::fn_zip;
+//!
+//! fn a(x: f32) -> f64
+//! {
+//!     (x as f64).sqrt()
+//! }
+//! fn b(x: u8) -> u8
+//! {
+//!     x + 1
+//! }
+//! fn c(x: i32) -> i32
+//! {
+//!     x * 2
+//! }
+//!
+//! let abc = fn_zip!(a, b, c);
+//!
+//! assert_eq!(abc((4.0,), (23,), (3,)), (a(4.0), b(23), c(3)));
+//! ```
+
+mod maybe_done;
 
 moddef::moddef!(
     flat(pub) mod {
         zip,
+        zip_all,
+        zip_select,
+        zip_try,
         zipped_fn,
-        join for cfg(feature = "async")
+        zipped_fn_all,
+        zipped_fn_select,
+        zipped_fn_try,
+        zipped_fn_tuple,
+        either,
+        join_all for cfg(all(feature = "async", feature = "alloc")),
+        join_try for cfg(feature = "async"),
+        join_tuple for cfg(feature = "async"),
+        select for cfg(all(feature = "async", feature = "alloc")),
+        zip_par for cfg(feature = "par"),
+        zipped_fn_par for cfg(feature = "par"),
+        zip_catch for cfg(feature = "std"),
+        zipped_fn_catch for cfg(feature = "std"),
+        abort for cfg(all(feature = "alloc", any(feature = "par", feature = "async"))),
+        fused for cfg(feature = "async")
     }
 );
 
@@ -220,4 +290,359 @@ mod tests
         assert_eq!(y_a, a(x_a));
         assert_eq!(y_b, b(x_b));
     }
+
+    #[test]
+    fn test_zip_all()
+    {
+        fn square(x: i32) -> i32
+        {
+            x*x
+        }
+
+        let all = [square, square, square].fn_zip_all();
+
+        assert_eq!(all.call([(1,), (2,), (3,)]), [1, 4, 9]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_zip_all_iter()
+    {
+        fn square(x: i32) -> i32
+        {
+            x*x
+        }
+
+        let fns: Vec<fn(i32) -> i32> = vec![square, square, square];
+        let all = fns.into_iter().fn_zip_all_iter();
+
+        assert_eq!(all.call(vec![(1,), (2,), (3,)]), vec![1, 4, 9]);
+    }
+
+    #[cfg(all(feature = "async", feature = "alloc"))]
+    #[test]
+    fn test_zip_all_async()
+    {
+        async fn square(x: i32) -> i32
+        {
+            x*x
+        }
+
+        let all = [square, square, square].fn_zip_all();
+
+        tokio_test::block_on(async {
+            assert_eq!(all.async_call([(1,), (2,), (3,)]).await, vec![1, 4, 9]);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_zip_catch()
+    {
+        fn a(x: f32) -> f64
+        {
+            (x as f64).sqrt()
+        }
+        fn b(_x: u8) -> u8
+        {
+            panic!("b always panics")
+        }
+
+        let ab = a.fn_zip_catch(b);
+        let (y_a, y_b) = ab(4.0, 23);
+
+        assert_eq!(y_a.unwrap(), a(4.0));
+        assert!(y_b.is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_try_fn_zip()
+    {
+        async fn a(x: f32) -> Result<f64, &'static str>
+        {
+            Ok((x as f64).sqrt())
+        }
+        async fn b(_x: u8) -> Result<u8, &'static str>
+        {
+            Err("b failed")
+        }
+
+        let ab = a.try_fn_zip(b);
+
+        tokio_test::block_on(async {
+            assert_eq!(ab.async_call_once((4.0, 23)).await, Err("b failed"));
+        });
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_join_fused()
+    {
+        async fn a(x: f32) -> f64
+        {
+            (x as f64).sqrt()
+        }
+        async fn b(x: u8) -> u8
+        {
+            x + 1
+        }
+
+        let ab = a.fn_zip(b);
+
+        tokio_test::block_on(async {
+            let join = ab.async_call_once((4.0, 23));
+            assert!(!join.is_terminated());
+
+            let (y_a, y_b) = join.await;
+            assert_eq!(y_a, a(4.0).await);
+            assert_eq!(y_b, b(23).await);
+        });
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_join_fairness()
+    {
+        use core::{cell::Cell, future::Future, pin::pin, task::{Context, Poll, RawWaker, RawWakerVTable, Waker}};
+
+        struct Pend;
+        impl Future for Pend
+        {
+            type Output = ();
+
+            fn poll(self: core::pin::Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output>
+            {
+                Poll::Pending
+            }
+        }
+
+        struct CountPolls<'a>(&'a Cell<u32>);
+        impl<'a> Future for CountPolls<'a>
+        {
+            type Output = ();
+
+            fn poll(self: core::pin::Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output>
+            {
+                self.0.set(self.0.get() + 1);
+                Poll::Pending
+            }
+        }
+
+        fn noop_waker() -> Waker
+        {
+            fn clone(_: *const ()) -> RawWaker
+            {
+                raw()
+            }
+            fn noop(_: *const ()) {}
+            fn raw() -> RawWaker
+            {
+                static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+                RawWaker::new(core::ptr::null(), &VTABLE)
+            }
+            unsafe { Waker::from_raw(raw()) }
+        }
+
+        let right_polls = Cell::new(0u32);
+        let mut join = pin!(Join::new(Pend, CountPolls(&right_polls)));
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        // `left` is always `Pending`. Before the starvation fix, the `||` short-circuit meant
+        // `right` was never even polled once `left` returned `Pending`.
+        let _ = join.as_mut().poll(&mut cx);
+        let _ = join.as_mut().poll(&mut cx);
+
+        assert_eq!(right_polls.get(), 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_join_fused_and_no_panic_on_double_poll()
+    {
+        use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+        fn noop_waker() -> Waker
+        {
+            fn clone(_: *const ()) -> RawWaker
+            {
+                raw()
+            }
+            fn noop(_: *const ()) {}
+            fn raw() -> RawWaker
+            {
+                static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+                RawWaker::new(core::ptr::null(), &VTABLE)
+            }
+            unsafe { Waker::from_raw(raw()) }
+        }
+
+        async fn a(x: f32) -> f64
+        {
+            (x as f64).sqrt()
+        }
+        async fn b(x: u8) -> u8
+        {
+            x + 1
+        }
+
+        let mut pair = core::pin::pin!(Join::new(a(4.0), b(23)));
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(!pair.is_terminated());
+
+        let Poll::Ready((y_a, y_b)) = pair.as_mut().poll(&mut cx)
+        else
+        {
+            panic!("expected the pair to resolve on the first poll")
+        };
+        assert_eq!(y_a, 2.0);
+        assert_eq!(y_b, 24);
+        assert!(pair.is_terminated());
+
+        // Polling again after completion must not panic, per the `FusedFuture` contract.
+        assert!(matches!(pair.as_mut().poll(&mut cx), Poll::Pending));
+    }
+
+    #[cfg(all(feature = "async", feature = "alloc"))]
+    #[test]
+    fn test_remote_handle_cancels_both_arms()
+    {
+        async fn a(x: f32) -> f64
+        {
+            (x as f64).sqrt()
+        }
+        async fn b(x: u8) -> u8
+        {
+            x + 1
+        }
+
+        let ab = a.fn_zip(b);
+
+        tokio_test::block_on(async {
+            let (abortable, handle) = ab.async_call_once((4.0, 23)).remote_handle();
+            drop(handle);
+
+            assert_eq!(abortable.await, Err(Aborted));
+        });
+    }
+
+    #[cfg(feature = "par")]
+    #[test]
+    fn test_abortable_par()
+    {
+        fn a(x: i32) -> i32
+        {
+            x
+        }
+        fn b(x: i32) -> i32
+        {
+            x
+        }
+
+        let (abortable, handle) = a.fn_zip_par(b).abortable();
+        handle.abort();
+
+        assert!(matches!(abortable.call_once((1, 2)), Err(AbortableParError::Aborted)));
+    }
+
+    #[test]
+    fn test_fn_zip_tuple()
+    {
+        fn a(x: f32) -> f64
+        {
+            (x as f64).sqrt()
+        }
+        fn b(x: u8) -> u8
+        {
+            x + 1
+        }
+        fn c(x: i32) -> i32
+        {
+            x * 2
+        }
+
+        let abc = fn_zip!(a, b, c);
+
+        assert_eq!(abc((4.0,), (23,), (3,)), (a(4.0), b(23), c(3)));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_fn_zip_tuple_async()
+    {
+        async fn a(x: f32) -> f64
+        {
+            (x as f64).sqrt()
+        }
+        async fn b(x: u8) -> u8
+        {
+            x + 1
+        }
+        async fn c(x: i32) -> i32
+        {
+            x * 2
+        }
+
+        let abc = fn_zip!(a, b, c);
+
+        tokio_test::block_on(async {
+            assert_eq!(abc.async_call_once(((4.0,), (23,), (3,))).await, (a(4.0).await, b(23).await, c(3).await));
+        });
+    }
+
+    #[cfg(all(feature = "async", feature = "alloc"))]
+    #[test]
+    fn test_fn_select_async()
+    {
+        async fn fast(x: i32) -> i32
+        {
+            x
+        }
+        async fn slow(x: u8) -> u8
+        {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            x
+        }
+
+        let ab = fast.fn_select(slow);
+
+        tokio_test::block_on(async {
+            match ab.async_call_once((1, 2)).await
+            {
+                Either::Left((y_a, right)) => {
+                    assert_eq!(y_a, 1);
+                    // the loser is handed back still-pending, so the caller can keep driving it
+                    assert_eq!(right.await, 2);
+                }
+                Either::Right((y_b, _left)) => panic!("slow side should not win, got {y_b}")
+            }
+        });
+    }
+
+    #[cfg(feature = "par")]
+    #[test]
+    fn test_select_par()
+    {
+        fn fast(x: i32) -> i32
+        {
+            x
+        }
+        fn slow(x: u8) -> u8
+        {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            x
+        }
+
+        let ab = fast.fn_zip_par(slow);
+
+        match ab.select_once((1, 2)).unwrap()
+        {
+            Either::Left(y_a) => assert_eq!(y_a, 1),
+            Either::Right(y_b) => panic!("slow side should not win, got {y_b}")
+        }
+    }
 }
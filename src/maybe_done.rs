@@ -0,0 +1,53 @@
+use core::{future::Future, pin::Pin, task::{Context, Poll}};
+
+/// A future that tracks whether it has completed yet, and lets its output be taken out once it
+/// has, without re-polling or re-running it.
+///
+/// Shared building block for every combinator in this crate that joins several futures at once
+/// ([Join](crate::Join), [JoinAll](crate::JoinAll), [TryJoinedPair](crate::TryJoinedPair) and
+/// the [JoinedTuple](crate::JoinedTuple3) family).
+pub(crate) enum MaybeDone<F: Future>
+{
+    Future(F),
+    Done(F::Output),
+    Taken,
+}
+
+impl<F: Future> MaybeDone<F>
+{
+    pub(crate) fn take_output(&mut self) -> Option<F::Output>
+    {
+        match *self
+        {
+            MaybeDone::Done(_) => match core::mem::replace(self, Self::Taken)
+            {
+                MaybeDone::Done(val) => Some(val),
+                _ => unreachable!(),
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<F: Future> Future for MaybeDone<F>
+{
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        // SAFETY: pinning is structural for `f`
+        unsafe {
+            match *self.as_mut().get_unchecked_mut()
+            {
+                MaybeDone::Future(ref mut f) => {
+                    let val = core::task::ready!(Pin::new_unchecked(f).poll(cx));
+                    self.set(Self::Done(val));
+                }
+                MaybeDone::Done(_) => {}
+                MaybeDone::Taken => unreachable!(),
+            }
+        }
+
+        Poll::Ready(())
+    }
+}
@@ -0,0 +1,62 @@
+use core::{future::Future, pin::Pin, task::{Context, Poll}};
+
+use alloc::boxed::Box;
+
+use super::*;
+
+/// A race between two futures, resolving as soon as either one finishes.
+///
+/// This is really only for use with the [SelectedFn] struct.
+/// If you need to race futures normally, use the `futures::select!` macro.
+///
+/// Both futures are boxed internally, so the still-pending one can be handed back to the
+/// caller by value once the other resolves, without requiring either future to be [Unpin] —
+/// an ordinary `async fn`'s generated future never is.
+pub struct Select<L, R>
+where
+    L: Future,
+    R: Future
+{
+    inner: Option<(Pin<Box<L>>, Pin<Box<R>>)>
+}
+
+impl<L, R> Select<L, R>
+where
+    L: Future,
+    R: Future
+{
+    pub fn new(left: L, right: R) -> Self
+    {
+        Self {
+            inner: Some((Box::pin(left), Box::pin(right)))
+        }
+    }
+}
+
+impl<L, R> Future for Select<L, R>
+where
+    L: Future,
+    R: Future
+{
+    type Output = Either<(L::Output, Pin<Box<R>>), (R::Output, Pin<Box<L>>)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        let (mut left, mut right) = self.inner
+            .take()
+            .expect("Select polled after completion");
+
+        match left.as_mut().poll(cx)
+        {
+            Poll::Ready(l) => Poll::Ready(Either::Left((l, right))),
+            Poll::Pending => match right.as_mut().poll(cx)
+            {
+                Poll::Ready(r) => Poll::Ready(Either::Right((r, left))),
+                Poll::Pending => {
+                    self.inner = Some((left, right));
+                    Poll::Pending
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use core::marker::Tuple;
+
+use super::*;
+
+/// Combines a fixed-size array of functions sharing the same argument tuple `X` into one
+/// callable that invokes every one of them and collects their outputs. Iterators of functions
+/// are handled by [FnZipAllIter] instead.
+///
+/// This is the variadic analog of [FnZip](crate::FnZip), modeled on futures-util's `join_all`.
+///
+/// # Example
+///
+/// ```rust
+/// use fn_zip::FnZipAll;
+///
+/// fn square(x: i32) -> i32
+/// {
+///     x * x
+/// }
+///
+/// let all = [square, square, square].fn_zip_all();
+///
+/// assert_eq!(all.call([(1,), (2,), (3,)]), [1, 4, 9]);
+/// ```
+#[const_trait]
+pub trait FnZipAll<X>
+where
+    X: Tuple
+{
+    type Output;
+
+    fn fn_zip_all(self) -> Self::Output;
+}
+
+impl<X, F, const N: usize> const FnZipAll<X> for [F; N]
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    type Output = ZippedFnAll<X, F, N>;
+
+    fn fn_zip_all(self) -> Self::Output
+    {
+        ZippedFnAll::new(self)
+    }
+}
+
+/// Combines an iterator of functions sharing the same argument tuple `X` into one callable
+/// that invokes every one of them in order and collects their outputs into a `Vec`.
+///
+/// This is the unsized counterpart to [FnZipAll] for when the number of functions is not known
+/// at compile-time.
+///
+/// # Example
+///
+/// ```rust
+/// use fn_zip::FnZipAllIter;
+///
+/// fn square(x: i32) -> i32
+/// {
+///     x * x
+/// }
+///
+/// let all = core::iter::repeat_with(|| square as fn(i32) -> i32).take(3).fn_zip_all_iter();
+///
+/// assert_eq!(all.call(vec![(1,), (2,), (3,)]), vec![1, 4, 9]);
+/// ```
+pub trait FnZipAllIter<X>
+where
+    X: Tuple
+{
+    type Item: FnOnce<X>;
+
+    fn fn_zip_all_iter(self) -> ZippedFnAllIter<X, Self::Item>;
+}
+
+impl<X, F, I> FnZipAllIter<X> for I
+where
+    X: Tuple,
+    F: FnOnce<X>,
+    I: Iterator<Item = F>
+{
+    type Item = F;
+
+    fn fn_zip_all_iter(self) -> ZippedFnAllIter<X, F>
+    {
+        ZippedFnAllIter::new(self.collect())
+    }
+}
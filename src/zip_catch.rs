@@ -0,0 +1,52 @@
+use core::marker::Tuple;
+
+use tupleops::TupleConcat;
+
+use super::*;
+
+/// Combines two functions into one that calls both, but isolates each call behind
+/// `std::panic::catch_unwind`, so that one side panicking does not unwind past the other.
+///
+/// # Example
+///
+/// ```rust
+/// use fn_zip::FnZipCatch;
+///
+/// fn a(x: f32) -> f64
+/// {
+///     (x as f64).sqrt()
+/// }
+/// fn b(_x: u8) -> u8
+/// {
+///     panic!("b always panics")
+/// }
+/// let ab = a.fn_zip_catch(b);
+///
+/// let (y_a, y_b) = ab(4.0, 23);
+///
+/// assert_eq!(y_a.unwrap(), a(4.0));
+/// assert!(y_b.is_err());
+/// ```
+#[const_trait]
+pub trait FnZipCatch<RX, LX, Rhs>: FnZip<RX, LX, Rhs>
+{
+    type OutputCatch;
+
+    fn fn_zip_catch(self, rhs: Rhs) -> <Self as FnZipCatch<RX, LX, Rhs>>::OutputCatch;
+}
+
+impl<RX, LX, LF, RF> const FnZipCatch<RX, LX, RF> for LF
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: FnOnce<LX>,
+    RF: FnOnce<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>
+{
+    type OutputCatch = ZippedFnCatch<LX, RX, LF, RF>;
+
+    fn fn_zip_catch(self, rhs: RF) -> <Self as FnZipCatch<RX, LX, RF>>::OutputCatch
+    {
+        ZippedFnCatch::from(self.fn_zip(rhs))
+    }
+}
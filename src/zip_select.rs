@@ -0,0 +1,75 @@
+use core::marker::Tuple;
+
+use tupleops::TupleConcat;
+
+use super::*;
+
+/// Combines two functions into one that resolves to whichever one finishes first, rather than
+/// waiting for both like [FnZip].
+///
+/// This is the lazy analog of futures' `select`.
+///
+/// # Example
+///
+#[cfg_attr(all(feature = "async", feature = "alloc"), doc = r##"
+```rust
+#![feature(fn_traits)]
+#![feature(async_fn_traits)]
+
+use fn_zip::{FnSelect, Either};
+
+async fn a(x: f32) -> f64
+{
+    (x as f64).sqrt()
+}
+async fn b(x: u8) -> u8
+{
+    x + 1
+}
+
+let ab = a.fn_select(b);
+let (x_a, x_b) = (4.0, 23);
+
+# tokio_test::block_on(async {
+match ab.async_call((x_a, x_b)).await
+{
+    Either::Left((y_a, _right)) => assert_eq!(y_a, a(x_a).await),
+    Either::Right((y_b, _left)) => assert_eq!(y_b, b(x_b).await)
+}
+# })
+```"##)]
+#[const_trait]
+pub trait FnSelect<RX, LX, Rhs>
+{
+    type Output;
+
+    fn fn_select_once(self, rhs: Rhs) -> Self::Output;
+    fn fn_select_mut<'a>(&'a mut self, rhs: Rhs) -> <&'a mut Self as FnSelect<RX, LX, Rhs>>::Output
+    where
+        &'a mut Self: ~const FnSelect<RX, LX, Rhs>
+    {
+        self.fn_select_once(rhs)
+    }
+    fn fn_select<'a>(&'a self, rhs: Rhs) -> <&'a Self as FnSelect<RX, LX, Rhs>>::Output
+    where
+        &'a Self: ~const FnSelect<RX, LX, Rhs>
+    {
+        self.fn_select_once(rhs)
+    }
+}
+
+impl<RX, LX, LF, RF> const FnSelect<RX, LX, RF> for LF
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: FnOnce<LX>,
+    RF: FnOnce<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>
+{
+    type Output = SelectedFn<LX, RX, LF, RF>;
+
+    fn fn_select_once(self, rhs: RF) -> Self::Output
+    {
+        SelectedFn::new(self, rhs)
+    }
+}
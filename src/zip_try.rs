@@ -0,0 +1,58 @@
+use core::marker::Tuple;
+
+use tupleops::TupleConcat;
+
+use super::*;
+
+/// Combines two functions into one that, when called asynchronously, short-circuits on the
+/// first `Err` instead of waiting for both sides to finish.
+///
+/// This mirrors tokio's `try_join`, and requires both functions' outputs to be `Result`s sharing
+/// the same error type.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(fn_traits)]
+/// #![feature(async_fn_traits)]
+///
+/// use fn_zip::FnZipTry;
+///
+/// async fn a(x: f32) -> Result<f64, &'static str>
+/// {
+///     Ok((x as f64).sqrt())
+/// }
+/// async fn b(_x: u8) -> Result<u8, &'static str>
+/// {
+///     Err("b failed")
+/// }
+///
+/// let ab = a.try_fn_zip(b);
+///
+/// # tokio_test::block_on(async {
+/// assert_eq!(ab.async_call_once((4.0, 23)).await, Err("b failed"));
+/// # })
+/// ```
+#[const_trait]
+pub trait FnZipTry<RX, LX, Rhs>: FnZip<RX, LX, Rhs>
+{
+    type OutputTry;
+
+    fn try_fn_zip(self, rhs: Rhs) -> <Self as FnZipTry<RX, LX, Rhs>>::OutputTry;
+}
+
+impl<RX, LX, LF, RF> const FnZipTry<RX, LX, RF> for LF
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: FnOnce<LX>,
+    RF: FnOnce<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>
+{
+    type OutputTry = ZippedFnTry<LX, RX, LF, RF>;
+
+    fn try_fn_zip(self, rhs: RF) -> <Self as FnZipTry<RX, LX, RF>>::OutputTry
+    {
+        ZippedFnTry::from(self.fn_zip(rhs))
+    }
+}
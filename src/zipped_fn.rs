@@ -5,6 +5,14 @@ use core::ops::{AsyncFnOnce, AsyncFnMut, AsyncFn};
 use tuple_split::TupleSplitInto;
 use tupleops::{TupleConcat, ConcatTuples};
 
+use crate::maybe_done::MaybeDone;
+
+#[cfg(feature = "async")]
+use crate::FusedFuture;
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+use crate::{AbortableJoin, AbortHandle, RemoteHandle};
+
 /// The result of zipping two functions together using [FnZip::fn_zip](FnZip::fn_zip).
 /// 
 /// Can be called as if a function, using the arguments of both zipped functions in sequence.
@@ -123,10 +131,7 @@ where
     extern "rust-call" fn async_call_once(self, args: ConcatTuples<LX, RX>) -> Self::CallOnceFuture
     {
         let (args_left, args_right) = tuple_split::split_tuple_into(args);
-        Join {
-            left: private::MaybeDone::Future(self.left.async_call_once(args_left)),
-            right: private::MaybeDone::Future(self.right.async_call_once(args_right))
-        }
+        Join::new(self.left.async_call_once(args_left), self.right.async_call_once(args_right))
     }
 }
 
@@ -146,10 +151,7 @@ where
     extern "rust-call" fn async_call_mut(&mut self, args: ConcatTuples<LX, RX>) -> Self::CallRefFuture<'_>
     {
         let (args_left, args_right) = tuple_split::split_tuple_into(args);
-        Join {
-            left: private::MaybeDone::Future(self.left.async_call_mut(args_left)),
-            right: private::MaybeDone::Future(self.right.async_call_mut(args_right))
-        }
+        Join::new(self.left.async_call_mut(args_left), self.right.async_call_mut(args_right))
     }
 }
 
@@ -165,21 +167,91 @@ where
     extern "rust-call" fn async_call(&self, args: ConcatTuples<LX, RX>) -> Self::CallRefFuture<'_>
     {
         let (args_left, args_right) = tuple_split::split_tuple_into(args);
-        Join {
-            left: private::MaybeDone::Future(self.left.async_call(args_left)),
-            right: private::MaybeDone::Future(self.right.async_call(args_right))
-        }
+        Join::new(self.left.async_call(args_left), self.right.async_call(args_right))
+    }
+}
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+impl<LX, RX, LF, RF> ZippedFn<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: AsyncFnOnce<LX>,
+    RF: AsyncFnOnce<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
+{
+    /// Like [async_call_once](Self::async_call_once), but the returned future can be cancelled
+    /// through the paired [AbortHandle] while it is being polled.
+    pub fn abortable_async_call_once(self, args: ConcatTuples<LX, RX>) -> (AbortableJoin<Join<LF::CallOnceFuture, RF::CallOnceFuture>>, AbortHandle)
+    {
+        AbortableJoin::new(self.async_call_once(args))
     }
 }
 
-/// A pair of joined futures
+/// A pair of joined futures.
+///
+/// This is what [ZippedFn::async_call_once](ZippedFn::async_call_once) (and `async_call_mut`/
+/// `async_call`) actually returns.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(fn_traits)]
+/// #![feature(async_fn_traits)]
+///
+/// use fn_zip::*;
+/// use core::ops::AsyncFn;
+///
+/// async fn a(x: f32) -> f64
+/// {
+///     (x as f64).sqrt()
+/// }
+/// async fn b(x: u8) -> u8
+/// {
+///     x + 1
+/// }
+///
+/// let ab = a.fn_zip(b);
+/// let (x_a, x_b) = (4.0, 23);
+///
+/// # tokio_test::block_on(async {
+/// // I don't know of any prettier way to call an async function...
+///
+/// let (y_a, y_b) = ab.async_call((x_a, x_b)).await;
+///
+/// assert_eq!(y_a, a(x_a).await);
+/// assert_eq!(y_b, b(x_b).await);
+/// # })
+/// ```
 pub struct Join<L, R>
 where
     L: Future,
     R: Future
 {
-    left: private::MaybeDone<L>,
-    right: private::MaybeDone<R>
+    left: MaybeDone<L>,
+    right: MaybeDone<R>,
+    /// When `true` (the default), `left` is always polled before `right` each round. Set this
+    /// to `false` to alternate which side is polled first, so neither side is systematically
+    /// favored when both are ready at the same time.
+    pub biased: bool,
+    toggle: core::cell::Cell<bool>
+}
+
+impl<L, R> Join<L, R>
+where
+    L: Future,
+    R: Future
+{
+    pub fn new(left: L, right: R) -> Self
+    {
+        Self {
+            left: MaybeDone::Future(left),
+            right: MaybeDone::Future(right),
+            biased: true,
+            toggle: core::cell::Cell::new(false)
+        }
+    }
 }
 
 impl<L, R> Future for Join<L, R>
@@ -191,26 +263,47 @@ where
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
     {
-        // This is pretty much the code for the `core::future::join!` macro made  limited to only two futures.
-        if unsafe {
-            !self.as_mut()
-                .map_unchecked_mut(|join| &mut join.left)
-                .poll(cx)
-                .is_ready()
-                || !self.as_mut()
-                .map_unchecked_mut(|join| &mut join.right)
-                .poll(cx)
-                .is_ready()
-            }
+        // This is pretty much the code for the `core::future::join!` macro made limited to only
+        // two futures. Each arm is polled independently of the other's readiness, and skipped
+        // once it's already `Done`, so neither side starves the other and finished arms aren't
+        // re-driven.
+        let join = unsafe {
+            self.as_mut()
+                .get_unchecked_mut()
+        };
+
+        if matches!(join.left, MaybeDone::Taken) || matches!(join.right, MaybeDone::Taken)
         {
+            // The output was already taken by an earlier `Ready` poll; per [FusedFuture],
+            // report no further progress instead of panicking.
             return Poll::Pending
         }
 
-        let join = unsafe {
-            self.as_mut()
-                .get_unchecked_mut()
+        let left_first = join.biased || {
+            let flip = join.toggle.get();
+            join.toggle.set(!flip);
+            flip
         };
 
+        let (left_done, right_done) = if left_first {
+            let left_done = matches!(join.left, MaybeDone::Done(_))
+                || unsafe { Pin::new_unchecked(&mut join.left) }.poll(cx).is_ready();
+            let right_done = matches!(join.right, MaybeDone::Done(_))
+                || unsafe { Pin::new_unchecked(&mut join.right) }.poll(cx).is_ready();
+            (left_done, right_done)
+        } else {
+            let right_done = matches!(join.right, MaybeDone::Done(_))
+                || unsafe { Pin::new_unchecked(&mut join.right) }.poll(cx).is_ready();
+            let left_done = matches!(join.left, MaybeDone::Done(_))
+                || unsafe { Pin::new_unchecked(&mut join.left) }.poll(cx).is_ready();
+            (left_done, right_done)
+        };
+
+        if !left_done || !right_done
+        {
+            return Poll::Pending
+        }
+
         Poll::Ready((
             join.left.take_output().unwrap(),
             join.right.take_output().unwrap()
@@ -218,54 +311,34 @@ where
     }
 }
 
-mod private
+#[cfg(feature = "async")]
+impl<L, R> FusedFuture for Join<L, R>
+where
+    L: Future,
+    R: Future
 {
-    use core::{future::Future, pin::Pin, task::{Context, Poll}};
-    
-    pub enum MaybeDone<F: Future>
+    fn is_terminated(&self) -> bool
     {
-        Future(F),
-        Done(F::Output),
-        Taken,
+        matches!(self.left, MaybeDone::Done(_) | MaybeDone::Taken)
+            && matches!(self.right, MaybeDone::Done(_) | MaybeDone::Taken)
     }
-    
-    impl<F: Future> MaybeDone<F>
+}
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+impl<L, R> Join<L, R>
+where
+    L: Future,
+    R: Future
+{
+    /// Hands over ownership of this joined future to an [AbortableJoin], returning a
+    /// [RemoteHandle] that cancels both arms as a unit when it is dropped.
+    ///
+    /// Unlike [abortable_async_call_once](ZippedFn::abortable_async_call_once), which requires
+    /// an explicit [AbortHandle::abort] call, the zipped call here is cancelled implicitly by
+    /// simply letting the handle go out of scope.
+    pub fn remote_handle(self) -> (AbortableJoin<Self>, RemoteHandle)
     {
-        pub fn take_output(&mut self) -> Option<F::Output>
-        {
-            match *self
-            {
-                MaybeDone::Done(_) => match core::mem::replace(self, Self::Taken)
-                {
-                    MaybeDone::Done(val) => Some(val),
-                    _ => unreachable!(),
-                },
-                _ => None,
-            }
-        }
+        let (abortable, handle) = AbortableJoin::new(self);
+        (abortable, RemoteHandle::new(handle))
     }
-    
-    impl<F: Future> Future for MaybeDone<F>
-    {
-        type Output = ();
-    
-        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
-        {
-            // SAFETY: pinning in structural for `f`
-            unsafe {
-                // Do not mix match ergonomics with unsafe.
-                match *self.as_mut().get_unchecked_mut()
-                {
-                    MaybeDone::Future(ref mut f) => {
-                        let val = core::task::ready!(Pin::new_unchecked(f).poll(cx));
-                        self.set(Self::Done(val));
-                    }
-                    MaybeDone::Done(_) => {}
-                    MaybeDone::Taken => unreachable!(),
-                }
-            }
-    
-            Poll::Ready(())
-        }
-    }    
 }
\ No newline at end of file
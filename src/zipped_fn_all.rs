@@ -0,0 +1,106 @@
+use core::marker::{PhantomData, Tuple};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::*;
+
+/// The result of zipping an array of functions together using [FnZipAll::fn_zip_all](FnZipAll::fn_zip_all).
+///
+/// Can be called with one argument tuple per function, returning an array of their outputs.
+pub struct ZippedFnAll<X, F, const N: usize>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    fns: [F; N],
+    marker: PhantomData<X>
+}
+
+impl<X, F, const N: usize> ZippedFnAll<X, F, N>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    pub const fn new(fns: [F; N]) -> Self
+    {
+        Self {
+            fns,
+            marker: PhantomData
+        }
+    }
+
+    pub fn call(self, args: [X; N]) -> [F::Output; N]
+    {
+        let mut fns = self.fns.into_iter();
+        let mut args = args.into_iter();
+
+        core::array::from_fn(|_| fns.next().unwrap().call_once(args.next().unwrap()))
+    }
+}
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+impl<X, F, const N: usize> ZippedFnAll<X, F, N>
+where
+    X: Tuple,
+    F: core::ops::AsyncFnOnce<X>
+{
+    pub fn async_call(self, args: [X; N]) -> JoinAll<F::CallOnceFuture>
+    {
+        let mut fns = self.fns.into_iter();
+        let mut args = args.into_iter();
+
+        JoinAll::new(core::array::from_fn::<_, N, _>(|_| fns.next().unwrap().async_call_once(args.next().unwrap())))
+    }
+}
+
+/// The result of zipping an iterator of functions together using [FnZipAllIter::fn_zip_all_iter](FnZipAllIter::fn_zip_all_iter).
+///
+/// Can be called with one argument tuple per function, returning a `Vec` of their outputs in
+/// the same order as the functions were given.
+#[cfg(feature = "alloc")]
+pub struct ZippedFnAllIter<X, F>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    fns: Vec<F>,
+    marker: PhantomData<X>
+}
+
+#[cfg(feature = "alloc")]
+impl<X, F> ZippedFnAllIter<X, F>
+where
+    X: Tuple,
+    F: FnOnce<X>
+{
+    pub const fn new(fns: Vec<F>) -> Self
+    {
+        Self {
+            fns,
+            marker: PhantomData
+        }
+    }
+
+    pub fn call(self, args: Vec<X>) -> Vec<F::Output>
+    {
+        self.fns.into_iter()
+            .zip(args)
+            .map(|(f, x)| f.call_once(x))
+            .collect()
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "async"))]
+impl<X, F> ZippedFnAllIter<X, F>
+where
+    X: Tuple,
+    F: core::ops::AsyncFnOnce<X>
+{
+    pub fn async_call(self, args: Vec<X>) -> JoinAll<F::CallOnceFuture>
+    {
+        JoinAll::new(self.fns.into_iter()
+            .zip(args)
+            .map(|(f, x)| f.async_call_once(x)))
+    }
+}
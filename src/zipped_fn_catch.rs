@@ -0,0 +1,134 @@
+use core::{marker::Tuple, panic::AssertUnwindSafe};
+
+use std::{any::Any, boxed::Box};
+
+use tupleops::{TupleConcat, ConcatTuples};
+use tuple_split::TupleSplitInto;
+
+use super::*;
+
+/// The result of zipping two functions together using [FnZipCatch::fn_zip_catch](FnZipCatch::fn_zip_catch).
+///
+/// Can be called as if a function, using the arguments of both zipped functions in sequence.
+/// Each side is invoked inside `std::panic::catch_unwind`, so a panicking function only poisons
+/// its own slot of the returned tuple instead of unwinding through the other.
+///
+/// # Example
+///
+/// ```rust
+/// use fn_zip::FnZipCatch;
+///
+/// fn a(x: f32) -> f64
+/// {
+///     (x as f64).sqrt()
+/// }
+/// fn b(_x: u8) -> u8
+/// {
+///     panic!("b always panics")
+/// }
+/// let ab = a.fn_zip_catch(b);
+///
+/// let (y_a, y_b) = ab(4.0, 23);
+///
+/// assert_eq!(y_a.unwrap(), a(4.0));
+/// assert!(y_b.is_err());
+/// ```
+pub struct ZippedFnCatch<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    zipped: ZippedFn<LX, RX, LF, RF>
+}
+
+impl<LX, RX, LF, RF> ZippedFnCatch<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    pub const fn from(zipped: ZippedFn<LX, RX, LF, RF>) -> Self
+    {
+        Self {
+            zipped
+        }
+    }
+}
+
+impl<LX, RX, LF, RF> const From<ZippedFn<LX, RX, LF, RF>> for ZippedFnCatch<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    fn from(zipped: ZippedFn<LX, RX, LF, RF>) -> Self
+    {
+        Self::from(zipped)
+    }
+}
+
+impl<LX, RX, LF, RF> FnOnce<ConcatTuples<LX, RX>> for ZippedFnCatch<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: FnOnce<LX>,
+    RF: FnOnce<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
+{
+    type Output = (Result<LF::Output, Box<dyn Any + Send>>, Result<RF::Output, Box<dyn Any + Send>>);
+
+    extern "rust-call" fn call_once(self, args: ConcatTuples<LX, RX>) -> Self::Output
+    {
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        let left = self.zipped.left;
+        let right = self.zipped.right;
+
+        (
+            std::panic::catch_unwind(AssertUnwindSafe(|| left.call_once(args_left))),
+            std::panic::catch_unwind(AssertUnwindSafe(|| right.call_once(args_right)))
+        )
+    }
+}
+
+impl<LX, RX, LF, RF> FnMut<ConcatTuples<LX, RX>> for ZippedFnCatch<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: FnMut<LX>,
+    RF: FnMut<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
+{
+    extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<LX, RX>) -> Self::Output
+    {
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        let left = &mut self.zipped.left;
+        let right = &mut self.zipped.right;
+
+        (
+            std::panic::catch_unwind(AssertUnwindSafe(|| left.call_mut(args_left))),
+            std::panic::catch_unwind(AssertUnwindSafe(|| right.call_mut(args_right)))
+        )
+    }
+}
+
+impl<LX, RX, LF, RF> Fn<ConcatTuples<LX, RX>> for ZippedFnCatch<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: Fn<LX>,
+    RF: Fn<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
+{
+    extern "rust-call" fn call(&self, args: ConcatTuples<LX, RX>) -> Self::Output
+    {
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        let left = &self.zipped.left;
+        let right = &self.zipped.right;
+
+        (
+            std::panic::catch_unwind(AssertUnwindSafe(|| left.call(args_left))),
+            std::panic::catch_unwind(AssertUnwindSafe(|| right.call(args_right)))
+        )
+    }
+}
@@ -1,6 +1,7 @@
 use core::{marker::{Tuple}, any::Any};
 
 use tupleops::{TupleConcat, ConcatTuples};
+use tuple_split::TupleSplitInto;
 
 use super::*;
 
@@ -83,7 +84,8 @@ where
     RX: Tuple + Send,
     LF: FnOnce<LX, Output: Send> + Send,
     RF: FnOnce<RX, Output: Send> + Send,
-    (LX, RX): TupleConcat<LX, RX, Type: Tuple>
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
 {
     type Output = Result<(LF::Output, RF::Output), (bool, ParError)>;
 
@@ -91,7 +93,7 @@ where
     {
         use std::thread::Builder;
         
-        let (args_left, args_right) = private::tuple_split_const(args);
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
 
         let mut builders = [Builder::new(), Builder::new()]
         .zip(self.thread_names.each_mut()
@@ -130,13 +132,14 @@ where
     RX: Tuple + Send,
     LF: FnMut<LX, Output: Send> + Send,
     RF: FnMut<RX, Output: Send> + Send,
-    (LX, RX): TupleConcat<LX, RX, Type: Tuple>
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
 {
     extern "rust-call" fn call_mut(&mut self, args: ConcatTuples<LX, RX>) -> Self::Output
     {
         use std::thread::Builder;
         
-        let (args_left, args_right) = private::tuple_split_const(args);
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
 
         let mut builders = [Builder::new(), Builder::new()]
         .zip(self.thread_names.each_ref()
@@ -175,13 +178,14 @@ where
     RX: Tuple + Send + Sync,
     LF: Fn<LX, Output: Send> + Send + Sync,
     RF: Fn<RX, Output: Send> + Send + Sync,
-    (LX, RX): TupleConcat<LX, RX, Type: Tuple>
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
 {
     extern "rust-call" fn call(&self, args: ConcatTuples<LX, RX>) -> Self::Output
     {
         use std::thread::Builder;
         
-        let (args_left, args_right) = private::tuple_split_const(args);
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
 
         let mut builders = [Builder::new(), Builder::new()]
         .zip(self.thread_names.each_ref()
@@ -218,4 +222,145 @@ pub enum ParError
 {
     SpawnThreadError(std::io::Error),
     JoinThreadError(Box<dyn Any + Send>)
+}
+
+impl<LX, RX, LF, RF> ZippedFnPar<LX, RX, LF, RF>
+where
+    LX: Tuple + Send,
+    RX: Tuple + Send,
+    LF: FnOnce<LX, Output: Send> + Send,
+    RF: FnOnce<RX, Output: Send> + Send,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
+{
+    /// Spawns both zipped functions as scoped threads and resolves to the output of whichever
+    /// one finishes first, identifying which side it was with [Either]. The scope still joins
+    /// the thread that lost the race before returning, so no thread outlives this call: the
+    /// call itself takes as long as the slower side, and the loser's output is discarded.
+    pub fn select_once(mut self, args: ConcatTuples<LX, RX>) -> Result<Either<LF::Output, RF::Output>, (bool, ParError)>
+    {
+        use std::{sync::mpsc, thread::Builder};
+
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+
+        let mut builders = [Builder::new(), Builder::new()]
+        .zip(self.thread_names.each_mut()
+            .zip(self.thread_stack_sizes.each_mut())
+        ).map(|(mut builder, (name, stack_size))| {
+            if let Some(name) = name.take()
+            {
+                builder = builder.name(name);
+            }
+            if let Some(stack_size) = stack_size.take()
+            {
+                builder = builder.stack_size(stack_size);
+            }
+            builder
+        }).into_iter();
+
+        let (tx_left, rx) = mpsc::channel();
+        let tx_right = tx_left.clone();
+
+        std::thread::scope(|scope| {
+            let handle_left = builders.next().unwrap().spawn_scoped(scope, move || {
+                let _ = tx_left.send(Either::Left(self.zipped.left.call_once(args_left)));
+            }).map_err(|err| (false, ParError::SpawnThreadError(err)))?;
+            let handle_right = builders.next().unwrap().spawn_scoped(scope, move || {
+                let _ = tx_right.send(Either::Right(self.zipped.right.call_once(args_right)));
+            }).map_err(|err| (true, ParError::SpawnThreadError(err)))?;
+
+            handle_left.join().map_err(|err| (false, ParError::JoinThreadError(err)))?;
+            handle_right.join().map_err(|err| (true, ParError::JoinThreadError(err)))?;
+
+            // SAFETY of the expect: both threads returned normally, so both have sent, and the
+            // channel yields whichever result was sent first.
+            let first = rx.recv().expect("a thread should have sent its result");
+
+            Ok(first)
+        })
+    }
+
+    /// Wraps this zipped pair so that it can be cancelled from another thread through the
+    /// returned [AbortHandle], before or while it is running.
+    ///
+    /// Since the zipped functions are opaque to this crate, cancellation cannot preempt a side
+    /// that has already started running on its thread - the abort flag is checked immediately
+    /// before each side is dispatched, and again once both threads have been joined.
+    pub fn abortable(self) -> (Abortable<LX, RX, LF, RF>, AbortHandle)
+    {
+        let (handle, registration) = AbortRegistration::new_pair();
+        (Abortable { par: self, registration }, handle)
+    }
+}
+
+/// The result of [ZippedFnPar::abortable](ZippedFnPar::abortable).
+pub struct Abortable<LX, RX, LF, RF>
+where
+    LX: Tuple + Send,
+    RX: Tuple + Send,
+    LF: FnOnce<LX, Output: Send> + Send,
+    RF: FnOnce<RX, Output: Send> + Send,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>
+{
+    par: ZippedFnPar<LX, RX, LF, RF>,
+    registration: AbortRegistration
+}
+
+pub enum AbortableParError
+{
+    Aborted,
+    Par(bool, ParError)
+}
+
+impl<LX, RX, LF, RF> Abortable<LX, RX, LF, RF>
+where
+    LX: Tuple + Send,
+    RX: Tuple + Send,
+    LF: FnOnce<LX, Output: Send> + Send,
+    RF: FnOnce<RX, Output: Send> + Send,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: TupleSplitInto<LX, RX>
+{
+    pub fn call_once(self, args: ConcatTuples<LX, RX>) -> Result<(LF::Output, RF::Output), AbortableParError>
+    {
+        if self.registration.is_aborted()
+        {
+            return Err(AbortableParError::Aborted);
+        }
+
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        let registration = &self.registration;
+        let par = self.par;
+
+        std::thread::scope(|scope| {
+            let handle_left = scope.spawn(|| {
+                if registration.is_aborted()
+                {
+                    return Err(Aborted);
+                }
+                Ok(par.zipped.left.call_once(args_left))
+            });
+            let handle_right = scope.spawn(|| {
+                if registration.is_aborted()
+                {
+                    return Err(Aborted);
+                }
+                Ok(par.zipped.right.call_once(args_right))
+            });
+
+            let left = handle_left.join().map_err(|err| AbortableParError::Par(false, ParError::JoinThreadError(err)))?;
+            let right = handle_right.join().map_err(|err| AbortableParError::Par(true, ParError::JoinThreadError(err)))?;
+
+            if registration.is_aborted()
+            {
+                return Err(AbortableParError::Aborted);
+            }
+
+            match (left, right)
+            {
+                (Ok(l), Ok(r)) => Ok((l, r)),
+                _ => Err(AbortableParError::Aborted)
+            }
+        })
+    }
 }
\ No newline at end of file
@@ -0,0 +1,115 @@
+use core::marker::{PhantomData, Tuple};
+
+use tupleops::{TupleConcat, ConcatTuples};
+
+use super::*;
+
+/// The result of combining two functions with [FnSelect::fn_select](FnSelect::fn_select).
+///
+/// Only the async call variants are meaningful, since racing a synchronous call against another
+/// makes no sense when neither can make progress concurrently.
+///
+/// # Example
+///
+#[cfg_attr(all(feature = "async", feature = "alloc"), doc = r##"
+```rust
+#![feature(fn_traits)]
+#![feature(async_fn_traits)]
+
+use fn_zip::{FnSelect, Either};
+
+async fn a(x: f32) -> f64
+{
+    (x as f64).sqrt()
+}
+async fn b(x: u8) -> u8
+{
+    x + 1
+}
+
+let ab = a.fn_select(b);
+let (x_a, x_b) = (4.0, 23);
+
+# tokio_test::block_on(async {
+match ab.async_call((x_a, x_b)).await
+{
+    Either::Left((y_a, _right)) => assert_eq!(y_a, a(x_a).await),
+    Either::Right((y_b, _left)) => assert_eq!(y_b, b(x_b).await)
+}
+# })
+```"##)]
+pub struct SelectedFn<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    pub left: LF,
+    pub right: RF,
+    marker: PhantomData<(LX, RX)>
+}
+
+impl<LX, RX, LF, RF> SelectedFn<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    pub const fn new(left: LF, right: RF) -> Self
+    {
+        Self {
+            left,
+            right,
+            marker: PhantomData
+        }
+    }
+}
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+impl<LX, RX, LF, RF> SelectedFn<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: core::ops::AsyncFnOnce<LX>,
+    RF: core::ops::AsyncFnOnce<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: tuple_split::TupleSplitInto<LX, RX>
+{
+    pub fn async_call_once(self, args: ConcatTuples<LX, RX>) -> Select<LF::CallOnceFuture, RF::CallOnceFuture>
+    {
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        Select::new(self.left.async_call_once(args_left), self.right.async_call_once(args_right))
+    }
+}
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+impl<LX, RX, LF, RF> SelectedFn<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: core::ops::AsyncFnMut<LX>,
+    RF: core::ops::AsyncFnMut<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: tuple_split::TupleSplitInto<LX, RX>
+{
+    pub fn async_call_mut(&mut self, args: ConcatTuples<LX, RX>) -> Select<LF::CallRefFuture<'_>, RF::CallRefFuture<'_>>
+    {
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        Select::new(self.left.async_call_mut(args_left), self.right.async_call_mut(args_right))
+    }
+}
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+impl<LX, RX, LF, RF> SelectedFn<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: core::ops::AsyncFn<LX>,
+    RF: core::ops::AsyncFn<RX>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: tuple_split::TupleSplitInto<LX, RX>
+{
+    pub fn async_call(&self, args: ConcatTuples<LX, RX>) -> Select<LF::CallRefFuture<'_>, RF::CallRefFuture<'_>>
+    {
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        Select::new(self.left.async_call(args_left), self.right.async_call(args_right))
+    }
+}
@@ -0,0 +1,55 @@
+use core::marker::Tuple;
+
+use tupleops::{TupleConcat, ConcatTuples};
+
+use super::*;
+
+/// The result of zipping two functions together using [FnZipTry::try_fn_zip](FnZipTry::try_fn_zip).
+pub struct ZippedFnTry<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    zipped: ZippedFn<LX, RX, LF, RF>
+}
+
+impl<LX, RX, LF, RF> ZippedFnTry<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    pub const fn from(zipped: ZippedFn<LX, RX, LF, RF>) -> Self
+    {
+        Self {
+            zipped
+        }
+    }
+}
+
+impl<LX, RX, LF, RF> const From<ZippedFn<LX, RX, LF, RF>> for ZippedFnTry<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple
+{
+    fn from(zipped: ZippedFn<LX, RX, LF, RF>) -> Self
+    {
+        Self::from(zipped)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<LX, RX, LF, RF, T, U, E> ZippedFnTry<LX, RX, LF, RF>
+where
+    LX: Tuple,
+    RX: Tuple,
+    LF: core::ops::AsyncFnOnce<LX, Output = Result<T, E>>,
+    RF: core::ops::AsyncFnOnce<RX, Output = Result<U, E>>,
+    (LX, RX): TupleConcat<LX, RX, Type: Tuple>,
+    ConcatTuples<LX, RX>: tuple_split::TupleSplitInto<LX, RX>
+{
+    pub fn async_call_once(self, args: ConcatTuples<LX, RX>) -> TryJoinedPair<LF::CallOnceFuture, RF::CallOnceFuture>
+    {
+        let (args_left, args_right) = tuple_split::split_tuple_into(args);
+        TryJoinedPair::new(self.zipped.left.async_call_once(args_left), self.zipped.right.async_call_once(args_right))
+    }
+}
@@ -0,0 +1,136 @@
+use core::marker::Tuple;
+
+macro_rules! impl_zipped_fn_tuple {
+    ($name:ident, $joined:ident; $($f:ident : $F:ident => $x:ident : $X:ident),+) => {
+        /// The result of zipping several functions together using the [fn_zip!](crate::fn_zip)
+        /// macro.
+        ///
+        /// Can be called with one argument tuple per function (grouped as a tuple-of-tuples),
+        /// returning a flat tuple of their outputs.
+        pub struct $name<$($F),+>
+        {
+            $($f: $F),+
+        }
+
+        impl<$($F),+> $name<$($F),+>
+        {
+            pub const fn new($($f: $F),+) -> Self
+            {
+                Self {
+                    $($f),+
+                }
+            }
+        }
+
+        impl<$($F, $X),+> FnOnce<($($X,)+)> for $name<$($F),+>
+        where
+            $($X: Tuple, $F: FnOnce<$X>),+
+        {
+            type Output = ($($F::Output,)+);
+
+            extern "rust-call" fn call_once(self, args: ($($X,)+)) -> Self::Output
+            {
+                let Self { $($f),+ } = self;
+                let ($($x,)+) = args;
+
+                ($($f.call_once($x),)+)
+            }
+        }
+
+        impl<$($F, $X),+> FnMut<($($X,)+)> for $name<$($F),+>
+        where
+            $($X: Tuple, $F: FnMut<$X>),+
+        {
+            extern "rust-call" fn call_mut(&mut self, args: ($($X,)+)) -> Self::Output
+            {
+                let ($($x,)+) = args;
+
+                ($(self.$f.call_mut($x),)+)
+            }
+        }
+
+        impl<$($F, $X),+> Fn<($($X,)+)> for $name<$($F),+>
+        where
+            $($X: Tuple, $F: Fn<$X>),+
+        {
+            extern "rust-call" fn call(&self, args: ($($X,)+)) -> Self::Output
+            {
+                let ($($x,)+) = args;
+
+                ($(self.$f.call($x),)+)
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<$($F, $X),+> $name<$($F),+>
+        where
+            $($X: Tuple, $F: core::ops::AsyncFnOnce<$X>),+
+        {
+            pub fn async_call_once(self, args: ($($X,)+)) -> $joined<$($F::CallOnceFuture),+>
+            {
+                let Self { $($f),+ } = self;
+                let ($($x,)+) = args;
+
+                $joined::new($($f.async_call_once($x)),+)
+            }
+        }
+    };
+}
+
+impl_zipped_fn_tuple!(ZippedFnTuple3, JoinedTuple3; f0: F0 => x0: X0, f1: F1 => x1: X1, f2: F2 => x2: X2);
+impl_zipped_fn_tuple!(ZippedFnTuple4, JoinedTuple4; f0: F0 => x0: X0, f1: F1 => x1: X1, f2: F2 => x2: X2, f3: F3 => x3: X3);
+impl_zipped_fn_tuple!(ZippedFnTuple5, JoinedTuple5; f0: F0 => x0: X0, f1: F1 => x1: X1, f2: F2 => x2: X2, f3: F3 => x3: X3, f4: F4 => x4: X4);
+impl_zipped_fn_tuple!(ZippedFnTuple6, JoinedTuple6; f0: F0 => x0: X0, f1: F1 => x1: X1, f2: F2 => x2: X2, f3: F3 => x3: X3, f4: F4 => x4: X4, f5: F5 => x5: X5);
+impl_zipped_fn_tuple!(ZippedFnTuple7, JoinedTuple7; f0: F0 => x0: X0, f1: F1 => x1: X1, f2: F2 => x2: X2, f3: F3 => x3: X3, f4: F4 => x4: X4, f5: F5 => x5: X5, f6: F6 => x6: X6);
+impl_zipped_fn_tuple!(ZippedFnTuple8, JoinedTuple8; f0: F0 => x0: X0, f1: F1 => x1: X1, f2: F2 => x2: X2, f3: F3 => x3: X3, f4: F4 => x4: X4, f5: F5 => x5: X5, f6: F6 => x6: X6, f7: F7 => x7: X7);
+
+/// Zips three to eight functions at once into a single callable, rather than nesting
+/// [fn_zip](crate::FnZip::fn_zip) pairwise.
+///
+/// The zipped function takes one argument tuple per function (as a tuple-of-tuples) and returns
+/// a flat tuple of their outputs. For more than eight functions, chain `fn_zip!` calls, or fall
+/// back to nested [fn_zip](crate::FnZip::fn_zip)/[fn_zip_all](crate::FnZipAll::fn_zip_all) calls.
+///
+/// # Example
+///
+/// ```rust
+/// use fn_zip::fn_zip;
+///
+/// fn a(x: f32) -> f64
+/// {
+///     (x as f64).sqrt()
+/// }
+/// fn b(x: u8) -> u8
+/// {
+///     x + 1
+/// }
+/// fn c(x: i32) -> i32
+/// {
+///     x * 2
+/// }
+///
+/// let abc = fn_zip!(a, b, c);
+///
+/// assert_eq!(abc((4.0,), (23,), (3,)), (a(4.0), b(23), c(3)));
+/// ```
+#[macro_export]
+macro_rules! fn_zip {
+    ($f0:expr, $f1:expr, $f2:expr $(,)?) => {
+        $crate::ZippedFnTuple3::new($f0, $f1, $f2)
+    };
+    ($f0:expr, $f1:expr, $f2:expr, $f3:expr $(,)?) => {
+        $crate::ZippedFnTuple4::new($f0, $f1, $f2, $f3)
+    };
+    ($f0:expr, $f1:expr, $f2:expr, $f3:expr, $f4:expr $(,)?) => {
+        $crate::ZippedFnTuple5::new($f0, $f1, $f2, $f3, $f4)
+    };
+    ($f0:expr, $f1:expr, $f2:expr, $f3:expr, $f4:expr, $f5:expr $(,)?) => {
+        $crate::ZippedFnTuple6::new($f0, $f1, $f2, $f3, $f4, $f5)
+    };
+    ($f0:expr, $f1:expr, $f2:expr, $f3:expr, $f4:expr, $f5:expr, $f6:expr $(,)?) => {
+        $crate::ZippedFnTuple7::new($f0, $f1, $f2, $f3, $f4, $f5, $f6)
+    };
+    ($f0:expr, $f1:expr, $f2:expr, $f3:expr, $f4:expr, $f5:expr, $f6:expr, $f7:expr $(,)?) => {
+        $crate::ZippedFnTuple8::new($f0, $f1, $f2, $f3, $f4, $f5, $f6, $f7)
+    };
+}